@@ -33,10 +33,16 @@
 
 pub use digest::{self, Digest};
 
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "oid")]
+use digest::const_oid::{AssociatedOid, ObjectIdentifier};
+
 use core::fmt;
 use digest::{
     block_buffer::Eager,
-    consts::{U16, U48, U64},
+    consts::{U2, U16, U48, U66},
     core_api::{
         AlgorithmName, Block, BlockSizeUser, Buffer, BufferKindUser, CoreWrapper, FixedOutputCore,
         OutputSizeUser, Reset, UpdateCore,
@@ -153,17 +159,38 @@ impl fmt::Debug for Md2Core {
     }
 }
 
+#[cfg(feature = "oid")]
+impl AssociatedOid for Md2Core {
+    /// The OID `1.2.840.113549.2.2` (`md2`, PKCS#1 digest algorithm).
+    const OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.2.2");
+}
+
+/// Magic byte marking a serialized [`Md2Core`] state blob.
+const STATE_MAGIC: u8 = 0xD2;
+/// Current serialization format version.
+const STATE_VERSION: u8 = 1;
+
 impl SerializableState for Md2Core {
-    type SerializedStateSize = U64;
+    // Two-byte header (magic + version) followed by the 48-byte state and the
+    // 16-byte checksum.
+    type SerializedStateSize = U66;
 
     fn serialize(&self) -> SerializedState<Self> {
-        GenericArray::<_, U48>::from(self.x).concat(self.checksum)
+        let header = GenericArray::<_, U2>::from([STATE_MAGIC, STATE_VERSION]);
+        header
+            .concat(GenericArray::<_, U48>::from(self.x))
+            .concat(self.checksum)
     }
 
     fn deserialize(
         serialized_state: &SerializedState<Self>,
     ) -> Result<Self, DeserializeStateError> {
-        let (serialized_x, serialized_checksum) = Split::<_, U48>::split(serialized_state);
+        let (header, rest) = Split::<_, U2>::split(serialized_state);
+        if header[0] != STATE_MAGIC || header[1] != STATE_VERSION {
+            return Err(DeserializeStateError);
+        }
+
+        let (serialized_x, serialized_checksum) = Split::<_, U48>::split(rest);
 
         Ok(Self {
             x: (*serialized_x).into(),
@@ -174,3 +201,41 @@ impl SerializableState for Md2Core {
 
 /// MD2 hasher state.
 pub type Md2 = CoreWrapper<Md2Core>;
+
+/// Read all bytes from `r` and return their MD2 digest.
+///
+/// Input is consumed in fixed-size chunks and fed to [`Digest::update`], so an
+/// arbitrarily large file or stream can be hashed without holding it in memory.
+///
+/// The `std` feature additionally enables [`digest`]'s `std::io::Write`
+/// implementation for [`Md2`], so a reader can also be piped in directly with
+/// [`std::io::copy`].
+#[cfg(feature = "std")]
+pub fn hash_reader<R: std::io::Read>(r: &mut R) -> std::io::Result<Output<Md2Core>> {
+    let mut hasher = Md2::new();
+    let mut buf = [0u8; 1024];
+    loop {
+        let n = r.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
+    Ok(hasher.finalize())
+}
+
+#[cfg(all(test, feature = "oid"))]
+mod oid_tests {
+    use super::*;
+
+    #[test]
+    fn md2_oid() {
+        // DER encoding of the `md2` digest algorithm identifier (1.2.840.113549.2.2).
+        assert_eq!(
+            Md2Core::OID.as_bytes(),
+            &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x02, 0x02]
+        );
+        // The OID is forwarded through the `CoreWrapper<Md2Core>` alias.
+        assert_eq!(Md2::OID, Md2Core::OID);
+    }
+}